@@ -0,0 +1,386 @@
+//! Data-race risk detection for `static mut` globals touched from spawned
+//! threads.
+//!
+//! The approach mirrors `taint`: no real call graph, just enough textual
+//! structure to answer "is this static read or written somewhere that runs
+//! concurrently with a spawned thread?" over a flat, single-file view. Two
+//! shapes count as concurrent with a spawned thread:
+//!
+//! 1. The spawned closure's own body, or anything it calls transitively.
+//! 2. The function that calls `thread::spawn` in the first place (and
+//!    anything *it* calls), since that function keeps running on its
+//!    original thread right alongside the one it just spawned.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::Detector;
+use crate::finding::{Finding, Severity};
+use crate::source::SourceFile;
+
+static STATIC_MUT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bstatic\s+mut\s+(\w+)").unwrap());
+
+fn mutable_static_idents(text: &str) -> Vec<String> {
+    STATIC_MUT_RE.captures_iter(text).map(|c| c[1].to_string()).collect()
+}
+
+/// Scan forward from `start` (the byte index right after an opening `{` or
+/// `(`) and return the byte index of its matching close, using simple depth
+/// counting. `open`/`close` are the two brace/paren bytes to balance.
+fn matching_close(text: &str, start: usize, open: u8, close: u8) -> usize {
+    let bytes = text.as_bytes();
+    let mut depth = 1usize;
+    let mut i = start;
+    while i < bytes.len() && depth > 0 {
+        if bytes[i] == open {
+            depth += 1;
+        } else if bytes[i] == close {
+            depth -= 1;
+        }
+        i += 1;
+    }
+    i.saturating_sub(1)
+}
+
+/// Very small brace-counting function splitter: returns `(name, body_start,
+/// body)` for every `fn name(...) { ... }` found in the file. `body_start` is
+/// the byte offset of `body`'s first character, kept around so findings can
+/// be anchored to an exact line without re-searching the file text for the
+/// body (which can go wrong if two bodies happen to be textually identical).
+static FN_SIGNATURE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"fn\s+(\w+)\s*\([^)]*\)[^{]*\{").unwrap());
+
+fn functions(text: &str) -> Vec<(String, usize, String)> {
+    let mut out = Vec::new();
+    for cap in FN_SIGNATURE_RE.captures_iter(text) {
+        let name = cap[1].to_string();
+        let open = cap.get(0).unwrap().end();
+        let close = matching_close(text, open, b'{', b'}');
+        out.push((name, open, text[open..close].to_string()));
+    }
+    out
+}
+
+static CALL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(\w+)\s*\(").unwrap());
+
+fn calls_in(body: &str) -> HashSet<String> {
+    CALL_RE.captures_iter(body).map(|c| c[1].to_string()).collect()
+}
+
+/// Byte offset and full argument text of every `thread::spawn(...)` call:
+/// `|| foo()`, `move || { foo(); }`, `move || { GLOBAL += 1; }`, etc. This is
+/// the closure passed to spawn, braces and all, so both "spawn calls another
+/// function that touches the static" and "spawn touches the static inline"
+/// can be detected from the same span.
+static SPAWN_OPEN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"thread::spawn\s*\(").unwrap());
+
+fn spawn_closures(text: &str) -> Vec<(usize, String)> {
+    SPAWN_OPEN_RE
+        .find_iter(text)
+        .map(|m| {
+            let open = m.end();
+            let close = matching_close(text, open, b'(', b')');
+            (open, text[open..close].to_string())
+        })
+        .collect()
+}
+
+/// Expand `entry` to the full set of functions reachable from it by call,
+/// using `graph` (name -> callees) as the adjacency list.
+fn reachable(entry: &str, graph: &HashMap<String, HashSet<String>>) -> HashSet<String> {
+    let mut seen = HashSet::new();
+    let mut stack = vec![entry.to_string()];
+    while let Some(name) = stack.pop() {
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        if let Some(callees) = graph.get(&name) {
+            for callee in callees {
+                if !seen.contains(callee) {
+                    stack.push(callee.clone());
+                }
+            }
+        }
+    }
+    seen
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Access {
+    Read,
+    Write,
+}
+
+impl Access {
+    fn describe(self) -> &'static str {
+        match self {
+            Access::Read => "read",
+            Access::Write => "mutated",
+        }
+    }
+}
+
+/// Is the reference to `static_name` ending at `ident_end` in `text` on the
+/// left-hand side of an assignment (`= `, `+=`, `-=`, ...) rather than just
+/// read? `==` is explicitly excluded so equality checks don't count as a
+/// write.
+fn classify_access(text: &str, ident_end: usize) -> Access {
+    let rest = text[ident_end..].trim_start();
+    let is_write = ["+=", "-=", "*=", "/=", "%=", "&=", "|=", "^="]
+        .iter()
+        .any(|op| rest.starts_with(op))
+        || (rest.starts_with('=') && !rest.starts_with("=="));
+    if is_write {
+        Access::Write
+    } else {
+        Access::Read
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Every byte offset in `body` where `ident` appears as a whole identifier
+/// (not part of a longer one). A plain substring scan instead of a
+/// `Regex::new` per call, since the needle varies per static name.
+fn word_occurrences<'a>(body: &'a str, ident: &'a str) -> impl Iterator<Item = usize> + 'a {
+    body.match_indices(ident).filter_map(move |(start, _)| {
+        let before_ok = body[..start].chars().next_back().is_none_or(|c| !is_ident_char(c));
+        let end = start + ident.len();
+        let after_ok = body[end..].chars().next().is_none_or(|c| !is_ident_char(c));
+        (before_ok && after_ok).then_some(start)
+    })
+}
+
+/// First access (read or write, preferring write if both occur) to
+/// `static_name` within `body`, plus the byte offset of that access.
+fn first_access(body: &str, static_name: &str) -> Option<(usize, Access)> {
+    let mut best: Option<(usize, Access)> = None;
+    for start in word_occurrences(body, static_name) {
+        let access = classify_access(body, start + static_name.len());
+        if access == Access::Write {
+            return Some((start, access));
+        }
+        best.get_or_insert((start, access));
+    }
+    best
+}
+
+pub struct SharedMutableStateDetector;
+
+impl Detector for SharedMutableStateDetector {
+    fn name(&self) -> &'static str {
+        "shared_mutable_state"
+    }
+
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        let statics = mutable_static_idents(&source.text);
+        if statics.is_empty() {
+            return Vec::new();
+        }
+
+        let fns = functions(&source.text);
+        let graph: HashMap<String, HashSet<String>> = fns
+            .iter()
+            .map(|(name, _, body)| (name.clone(), calls_in(body)))
+            .collect();
+
+        let closures = spawn_closures(&source.text);
+
+        let mut spawn_reachable = HashSet::new();
+        for (_, closure_body) in &closures {
+            for entry in calls_in(closure_body) {
+                spawn_reachable.extend(reachable(&entry, &graph));
+            }
+        }
+        // A function that itself calls `thread::spawn` keeps running
+        // concurrently with the thread it just spawned, so anything it (or
+        // anything it calls) touches afterwards is just as much at risk as
+        // the spawned closure's own body — e.g. `main` spawning a thread and
+        // then separately calling a function that mutates a shared static.
+        for (name, _, body) in &fns {
+            if SPAWN_OPEN_RE.is_match(body) {
+                spawn_reachable.extend(reachable(name, &graph));
+            }
+        }
+
+        let mut findings = Vec::new();
+        // A top-level function's body textually contains its spawned
+        // closures too, so the two passes below can land on the very same
+        // source line (e.g. `main` both *is* spawn-reachable and contains
+        // the closure literally touching the static); track lines already
+        // reported per static so that doesn't show up twice.
+        let mut reported: HashSet<(String, usize)> = HashSet::new();
+
+        // Statics touched directly inside a spawned closure's own body.
+        for (start, closure_body) in &closures {
+            for static_name in &statics {
+                if let Some((offset, access)) = first_access(closure_body, static_name) {
+                    let line = source.line_at(start + offset);
+                    if reported.insert((static_name.clone(), line)) {
+                        findings.push(self.finding(source, static_name, "the spawned closure", access, line));
+                    }
+                }
+            }
+        }
+
+        // Statics touched inside a function the spawned closure(s) reach.
+        for (name, body_start, body) in &fns {
+            if !spawn_reachable.contains(name) {
+                continue;
+            }
+            for static_name in &statics {
+                if let Some((offset, access)) = first_access(body, static_name) {
+                    let line = source.line_at(body_start + offset);
+                    if reported.insert((static_name.clone(), line)) {
+                        findings.push(self.finding(source, static_name, &format!("`{name}`"), access, line));
+                    }
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+impl SharedMutableStateDetector {
+    fn finding(&self, source: &SourceFile, static_name: &str, where_: &str, access: Access, line: usize) -> Finding {
+        Finding::new(
+            self.name(),
+            Severity::High,
+            source.display_path(),
+            line,
+            format!(
+                "`{static_name}` is {} in {where_}, which is reachable from a spawned thread; wrap it in a Mutex/Arc<Mutex<_>> or an atomic",
+                access.describe()
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_static_mutated_in_spawn_reachable_function() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            r#"
+static mut GLOBAL_COUNTER: i32 = 5;
+
+fn infinite_loop() {
+    unsafe {
+        GLOBAL_COUNTER += 1;
+    }
+}
+
+fn main() {
+    thread::spawn(|| infinite_loop());
+}
+"#,
+        );
+        let findings = SharedMutableStateDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("mutated"));
+    }
+
+    #[test]
+    fn flags_static_touched_directly_in_block_bodied_closure() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            r#"
+static mut GLOBAL_COUNTER: i32 = 5;
+
+fn main() {
+    thread::spawn(move || {
+        GLOBAL_COUNTER += 1;
+    });
+}
+"#,
+        );
+        let findings = SharedMutableStateDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("the spawned closure"));
+    }
+
+    #[test]
+    fn flags_static_mutated_by_a_sibling_call_in_the_spawning_function() {
+        // Mirrors the fixture: `main` spawns a thread running an unrelated
+        // function, but `main` itself also calls something that mutates the
+        // static — still a race, since both threads run concurrently.
+        let source = SourceFile::from_text(
+            "test.rs",
+            r#"
+static mut GLOBAL_COUNTER: i32 = 5;
+
+fn infinite_loop() {
+    unsafe {
+        GLOBAL_COUNTER += 1;
+    }
+}
+
+fn async_method() {
+    println!("does not touch the static");
+}
+
+fn main() {
+    infinite_loop();
+    thread::spawn(|| async_method());
+}
+"#,
+        );
+        let findings = SharedMutableStateDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("mutated"));
+    }
+
+    #[test]
+    fn does_not_flag_static_unreachable_from_spawn() {
+        // `infinite_loop` mutates the static but nothing calls it, and
+        // nothing spawns a thread, so there's no concurrent access to flag.
+        let source = SourceFile::from_text(
+            "test.rs",
+            r#"
+static mut GLOBAL_COUNTER: i32 = 5;
+
+fn infinite_loop() {
+    unsafe {
+        GLOBAL_COUNTER += 1;
+    }
+}
+
+fn async_method() {
+    println!("no static touched here");
+}
+
+fn main() {
+    async_method();
+}
+"#,
+        );
+        assert!(SharedMutableStateDetector.run(&source).is_empty());
+    }
+
+    #[test]
+    fn distinguishes_read_from_write() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            r#"
+static mut GLOBAL_COUNTER: i32 = 5;
+
+fn main() {
+    thread::spawn(move || {
+        println!("{}", GLOBAL_COUNTER);
+    });
+}
+"#,
+        );
+        let findings = SharedMutableStateDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("read"));
+        assert!(!findings[0].message.contains("mutated"));
+    }
+}
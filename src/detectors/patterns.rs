@@ -0,0 +1,459 @@
+//! Simple pattern detectors. Most scan a file line by line looking for a
+//! textual signature of a risky construct; `recursion` and `multiple_returns`
+//! match against the whole file text. These are cheap and
+//! have no notion of control flow; `taint` and other multi-line passes layer
+//! on top where that's not good enough.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::Detector;
+use crate::finding::{Finding, Severity};
+use crate::source::SourceFile;
+
+fn lines_matching<'a>(source: &'a SourceFile, re: &'a Regex) -> impl Iterator<Item = (usize, &'a str)> {
+    source
+        .lines
+        .iter()
+        .enumerate()
+        .filter(move |(_, line)| re.is_match(line))
+        .map(|(i, line)| (i + 1, line.as_str()))
+}
+
+/// How many times does `haystack` call `name(...)` as a whole identifier
+/// immediately followed by `(` (ignoring whitespace)? Used instead of a
+/// per-call `Regex::new` where the needle varies per invocation (e.g. per
+/// function name).
+fn count_calls(haystack: &str, name: &str) -> usize {
+    fn is_ident_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+    haystack
+        .match_indices(name)
+        .filter(|&(start, _)| {
+            let before_ok = haystack[..start].chars().next_back().is_none_or(|c| !is_ident_char(c));
+            let end = start + name.len();
+            let after_ok = !haystack[end..].chars().next().is_some_and(is_ident_char);
+            let followed_by_call = haystack[end..].trim_start().starts_with('(');
+            before_ok && after_ok && followed_by_call
+        })
+        .count()
+}
+
+static GLOBAL_VARS_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bstatic\s+mut\s+\w+").unwrap());
+
+pub struct GlobalVarsDetector;
+impl Detector for GlobalVarsDetector {
+    fn name(&self) -> &'static str {
+        "global_vars"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &GLOBAL_VARS_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    line,
+                    "mutable global state; prefer passing state explicitly or using a Mutex",
+                )
+            })
+            .collect()
+    }
+}
+
+static FN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"fn\s+(\w+)\s*\([^)]*\)[^{]*\{").unwrap());
+
+/// Scan forward from `open` (the byte index right after a function's opening
+/// `{`) and return its body text, using simple brace-depth counting.
+fn fn_body_at(text: &str, open: usize) -> &str {
+    let bytes = text.as_bytes();
+    let mut depth = 1usize;
+    let mut i = open;
+    while i < bytes.len() && depth > 0 {
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    &text[open..i.saturating_sub(1)]
+}
+
+pub struct RecursionDetector;
+impl Detector for RecursionDetector {
+    fn name(&self) -> &'static str {
+        "recursion"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for cap in FN_RE.captures_iter(&source.text) {
+            let name = &cap[1];
+            let whole = cap.get(0).unwrap();
+            let body = fn_body_at(&source.text, whole.end());
+            // A self-call inside the function's own body, not anywhere else
+            // in the file, means it's (apparently) recursive.
+            if count_calls(body, name) > 0 {
+                let line = source.line_at(whole.start());
+                findings.push(Finding::new(
+                    self.name(),
+                    Severity::Low,
+                    source.display_path(),
+                    line,
+                    format!("`{name}` appears to call itself; watch for unbounded stack growth"),
+                ));
+            }
+        }
+        findings
+    }
+}
+
+pub struct MultipleReturnsDetector;
+static RETURN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\breturn\b").unwrap());
+
+impl Detector for MultipleReturnsDetector {
+    fn name(&self) -> &'static str {
+        "multiple_returns"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        let count = RETURN_RE.find_iter(&source.text).count();
+        if count > 1 {
+            vec![Finding::new(
+                self.name(),
+                Severity::Low,
+                source.display_path(),
+                1,
+                format!("{count} `return` statements in this file; consider a single exit point per function"),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+pub struct DynamicMemoryDetector;
+static VEC_MACRO_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bvec!\s*\[").unwrap());
+
+impl Detector for DynamicMemoryDetector {
+    fn name(&self) -> &'static str {
+        "dynamic_memory"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &VEC_MACRO_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Low,
+                    source.display_path(),
+                    line,
+                    "heap allocation via vec!; confirm the size is bounded",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct UnboundedLoopsDetector;
+static LOOP_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bloop\s*\{").unwrap());
+
+impl Detector for UnboundedLoopsDetector {
+    fn name(&self) -> &'static str {
+        "unbounded_loops"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &LOOP_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Low,
+                    source.display_path(),
+                    line,
+                    "`loop` with no stated bound; confirm there is a reachable exit",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct ComplexFlowDetector;
+static BREAK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bbreak\b").unwrap());
+
+impl Detector for ComplexFlowDetector {
+    fn name(&self) -> &'static str {
+        "complex_flow"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &BREAK_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Low,
+                    source.display_path(),
+                    line,
+                    "`break` inside a loop adds a non-obvious exit path",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct AsyncRiskDetector;
+static THREAD_SPAWN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bthread::spawn\s*\(").unwrap());
+
+impl Detector for AsyncRiskDetector {
+    fn name(&self) -> &'static str {
+        "async_risk"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &THREAD_SPAWN_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    line,
+                    "spawned thread; check it doesn't capture shared mutable state unsynchronized",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct SetTimeoutDetector;
+static THREAD_SLEEP_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bthread::sleep\s*\(").unwrap());
+
+impl Detector for SetTimeoutDetector {
+    fn name(&self) -> &'static str {
+        "set_timeout"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &THREAD_SLEEP_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Low,
+                    source.display_path(),
+                    line,
+                    "timing-dependent sleep; behavior may be flaky under load",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct EvalUsageDetector;
+static UNSAFE_BLOCK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bunsafe\s*\{").unwrap());
+
+impl Detector for EvalUsageDetector {
+    fn name(&self) -> &'static str {
+        "eval_usage"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &UNSAFE_BLOCK_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    line,
+                    "`unsafe` block; verify the invariants it relies on are actually upheld",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct NestedConditionalsDetector;
+impl Detector for NestedConditionalsDetector {
+    fn name(&self) -> &'static str {
+        "nested_conditionals"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut if_depth_stack: Vec<usize> = Vec::new();
+        for (i, line) in source.lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            let indent = line.len() - trimmed.len();
+            if trimmed.starts_with("if ") || trimmed.starts_with("if(") {
+                if if_depth_stack.iter().any(|&d| d < indent) {
+                    findings.push(Finding::new(
+                        self.name(),
+                        Severity::Low,
+                        source.display_path(),
+                        i + 1,
+                        "nested `if` adds branching depth; consider an early return or guard clause",
+                    ));
+                }
+                if_depth_stack.push(indent);
+            }
+        }
+        findings
+    }
+}
+
+pub struct UnsafeInputDetector;
+static STDIN_READ_LINE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"stdin\s*\(\s*\)\s*\.\s*read_line").unwrap());
+
+impl Detector for UnsafeInputDetector {
+    fn name(&self) -> &'static str {
+        "unsafe_input"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &STDIN_READ_LINE_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    line,
+                    "stdin read with no validation of its contents before use",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct UnsafeFileOpDetector;
+static FILE_OPEN_UNWRAP_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"File::open\s*\([^)]*\)\s*\.unwrap\s*\(\s*\)").unwrap());
+
+impl Detector for UnsafeFileOpDetector {
+    fn name(&self) -> &'static str {
+        "unsafe_file_op"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &FILE_OPEN_UNWRAP_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    line,
+                    "file open panics on failure instead of handling the error",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct NetworkCallDetector;
+static TCP_STREAM_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"TcpStream::(connect|read)\s*\(").unwrap());
+
+impl Detector for NetworkCallDetector {
+    fn name(&self) -> &'static str {
+        "network_call"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &TCP_STREAM_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::Medium,
+                    source.display_path(),
+                    line,
+                    "network call with no visible error handling",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct WeakCryptoDetector;
+static MD5_COMPUTE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bmd5::compute\s*\(").unwrap());
+
+impl Detector for WeakCryptoDetector {
+    fn name(&self) -> &'static str {
+        "weak_crypto"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &MD5_COMPUTE_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::High,
+                    source.display_path(),
+                    line,
+                    "MD5 is cryptographically broken; use SHA-256 or better",
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct UnsanitizedExecDetector;
+static SH_COMMAND_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Command::new\s*\(\s*"sh"\s*\)"#).unwrap());
+
+impl Detector for UnsanitizedExecDetector {
+    fn name(&self) -> &'static str {
+        "unsanitized_exec"
+    }
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        lines_matching(source, &SH_COMMAND_RE)
+            .map(|(line, _)| {
+                Finding::new(
+                    self.name(),
+                    Severity::High,
+                    source.display_path(),
+                    line,
+                    "shell command built with string concatenation; risk of command injection",
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_vars_flags_static_mut() {
+        let source = SourceFile::from_text("test.rs", "static mut GLOBAL_COUNTER: i32 = 5;\n");
+        let findings = GlobalVarsDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].line, 1);
+    }
+
+    #[test]
+    fn recursion_detects_self_call() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn factorial(n: i32) -> i32 {\n    factorial(n - 1) * n\n}\n",
+        );
+        let findings = RecursionDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn recursion_ignores_prefix_identifier_as_substring() {
+        // `fact` must not match inside `factorial` just because it's a
+        // textual substring of it; only whole-identifier calls count.
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn fact(n: i32) -> i32 {\n    factorial(n)\n}\n\nfn factorial(n: i32) -> i32 { 1 }\n",
+        );
+        let findings = RecursionDetector.run(&source);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn recursion_ignores_function_called_once_from_elsewhere() {
+        // `helper` is declared and called exactly once, from a different
+        // function; that's an ordinary call, not recursion.
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn helper(n: i32) -> i32 {\n    n + 1\n}\n\nfn main() {\n    helper(5);\n}\n",
+        );
+        let findings = RecursionDetector.run(&source);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn unsanitized_exec_flags_sh_command() {
+        let source = SourceFile::from_text("test.rs", r#"Command::new("sh").arg("-c");"#);
+        let findings = UnsanitizedExecDetector.run(&source);
+        assert_eq!(findings.len(), 1);
+    }
+}
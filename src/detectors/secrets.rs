@@ -0,0 +1,304 @@
+//! Secret, key, and nonce scanning.
+//!
+//! Two independent signals feed into the same `exposed_secrets` rule, each
+//! applied to both string literals (`"..."`/`b"..."`) and `[u8]`-shaped
+//! array literals (`[0x00, 0x11, ...]`, `[13, 37, ...]`):
+//!
+//! 1. A literal assigned to an identifier whose name looks like a
+//!    credential (`key`, `secret`, `token`, `nonce`, `iv`, `password`,
+//!    `passwd`), regardless of how random the value looks.
+//! 2. Any literal whose Shannon entropy clears `ENTROPY_THRESHOLD` bits per
+//!    character (or per byte, for array literals) and whose length clears
+//!    `MIN_LENGTH` (`BYTE_ARRAY_MIN_LENGTH` for arrays) — catches material
+//!    assigned to an innocuous-looking name.
+//!
+//! A line can be suppressed with a trailing `// spc-allow: exposed_secrets`
+//! comment, same convention as the rest of the detectors would use for
+//! inline allowlisting.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::Detector;
+use crate::finding::{Finding, Severity};
+use crate::source::SourceFile;
+
+const ENTROPY_THRESHOLD: f64 = 4.0;
+const MIN_LENGTH: usize = 20;
+/// Array literals are denser than text (each element is a full byte, vs.
+/// ~4-6 bits/char for hex/base64 text), and 16 is also a natural key length
+/// (128 bits) — low enough to let a fully-random 16-byte array clear
+/// `ENTROPY_THRESHOLD` (`log2(16) == 4.0` at best, with 16 distinct values).
+const BYTE_ARRAY_MIN_LENGTH: usize = 16;
+const SUPPRESS_MARKER: &str = "spc-allow: exposed_secrets";
+
+fn shannon_entropy_of<T: Eq + std::hash::Hash>(items: impl Iterator<Item = T>, len: usize) -> f64 {
+    if len == 0 {
+        return 0.0;
+    }
+    let len = len as f64;
+    let mut counts = std::collections::HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0u32) += 1;
+    }
+    -counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / len;
+            p * p.log2()
+        })
+        .sum::<f64>()
+}
+
+fn shannon_entropy(s: &str) -> f64 {
+    shannon_entropy_of(s.chars(), s.chars().count())
+}
+
+fn shannon_entropy_bytes(bytes: &[u8]) -> f64 {
+    shannon_entropy_of(bytes.iter().copied(), bytes.len())
+}
+
+/// Mask all but the first/last couple of characters so the finding itself
+/// doesn't leak the credential it's reporting.
+fn masked_preview(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 4 {
+        return "*".repeat(chars.len());
+    }
+    let head: String = chars[..2].iter().collect();
+    let tail: String = chars[chars.len() - 2..].iter().collect();
+    format!("{head}{}{tail}", "*".repeat(chars.len() - 4))
+}
+
+/// Mask all but the first/last couple of bytes, rendered as hex, so the
+/// finding doesn't leak the key/nonce it's reporting.
+fn masked_preview_bytes(bytes: &[u8]) -> String {
+    if bytes.len() <= 4 {
+        return "*".repeat(bytes.len() * 2);
+    }
+    let head: String = bytes[..2].iter().map(|b| format!("{b:02x}")).collect();
+    let tail: String = bytes[bytes.len() - 2..].iter().map(|b| format!("{b:02x}")).collect();
+    format!("{head}{}{tail}", "*".repeat((bytes.len() - 4) * 2))
+}
+
+struct StringLiteral<'a> {
+    ident: Option<&'a str>,
+    value: &'a str,
+}
+
+static ASSIGN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"([A-Za-z_]\w*)\s*:?\s*(?:&str|&\[u8\])?\s*=\s*(?:b)?"([^"]+)""#).unwrap());
+static BARE_LITERAL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(?:b)?"([^"]+)""#).unwrap());
+
+/// Find `ident = "value"` and bare `"value"` string literals on a line.
+fn string_literals(line: &str) -> Vec<StringLiteral<'_>> {
+    let mut out: Vec<StringLiteral> = Vec::new();
+    let mut covered = Vec::new();
+    for cap in ASSIGN_RE.captures_iter(line) {
+        let m = cap.get(0).unwrap();
+        covered.push((m.start(), m.end()));
+        out.push(StringLiteral {
+            ident: Some(line[cap.get(1).unwrap().range()].as_ref()),
+            value: line[cap.get(2).unwrap().range()].as_ref(),
+        });
+    }
+
+    for cap in BARE_LITERAL_RE.captures_iter(line) {
+        let m = cap.get(0).unwrap();
+        if covered.iter().any(|&(s, e)| m.start() >= s && m.end() <= e) {
+            continue;
+        }
+        out.push(StringLiteral {
+            ident: None,
+            value: line[cap.get(1).unwrap().range()].as_ref(),
+        });
+    }
+    out
+}
+
+struct ByteArrayLiteral<'a> {
+    ident: &'a str,
+    bytes: Vec<u8>,
+}
+
+static BYTE_ARRAY_ASSIGN_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"([A-Za-z_]\w*)\s*(?::\s*[^=]+)?=\s*\[\s*((?:0[xX][0-9a-fA-F]{1,2}|\d{1,3})(?:\s*,\s*(?:0[xX][0-9a-fA-F]{1,2}|\d{1,3}))*)\s*,?\s*\]").unwrap()
+});
+
+fn parse_byte(token: &str) -> Option<u8> {
+    match token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+        Some(hex) => u8::from_str_radix(hex, 16).ok(),
+        None => token.parse().ok(),
+    }
+}
+
+/// Find `ident = [0x.., ..]` / `ident: [u8; N] = [13, 37, ..]` byte-array
+/// literals on a line.
+fn byte_array_literals(line: &str) -> Vec<ByteArrayLiteral<'_>> {
+    BYTE_ARRAY_ASSIGN_RE
+        .captures_iter(line)
+        .filter_map(|cap| {
+            let ident = &line[cap.get(1).unwrap().range()];
+            let body = &line[cap.get(2).unwrap().range()];
+            let bytes = body.split(',').map(|tok| parse_byte(tok.trim())).collect::<Option<Vec<u8>>>()?;
+            Some(ByteArrayLiteral { ident, bytes })
+        })
+        .collect()
+}
+
+static CREDENTIAL_NAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)key|secret|token|nonce|\biv\b|password|passwd").unwrap());
+
+fn looks_like_credential_name(ident: &str) -> bool {
+    CREDENTIAL_NAME_RE.is_match(ident)
+}
+
+static KEY_MATERIAL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z0-9+/=]{20,}$").unwrap());
+
+fn looks_like_key_material(value: &str) -> bool {
+    KEY_MATERIAL_RE.is_match(value)
+}
+
+pub struct ExposedSecretsDetector;
+
+impl Detector for ExposedSecretsDetector {
+    fn name(&self) -> &'static str {
+        "exposed_secrets"
+    }
+
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        for (i, line) in source.lines.iter().enumerate() {
+            if line.contains(SUPPRESS_MARKER) {
+                continue;
+            }
+
+            for lit in string_literals(line) {
+                let by_name = lit.ident.is_some_and(looks_like_credential_name);
+                let entropy = shannon_entropy(lit.value);
+                let by_entropy = lit.value.chars().count() >= MIN_LENGTH && entropy >= ENTROPY_THRESHOLD;
+
+                if !by_name && !by_entropy {
+                    continue;
+                }
+
+                let ident = lit.ident.unwrap_or("<literal>");
+                let reason = if by_name && by_entropy {
+                    format!("looks like a {ENTROPY_THRESHOLD:.1}+ bit/char credential assigned to `{ident}`")
+                } else if by_name {
+                    format!("hardcoded value assigned to credential-shaped identifier `{ident}`")
+                } else if looks_like_key_material(lit.value) {
+                    format!("{entropy:.1} bit/char entropy hex/base64-looking literal assigned to `{ident}` looks like key material")
+                } else {
+                    format!("{entropy:.1} bit/char entropy literal assigned to `{ident}` exceeds the {ENTROPY_THRESHOLD:.1} bit/char threshold")
+                };
+
+                findings.push(Finding::new(
+                    self.name(),
+                    Severity::High,
+                    source.display_path(),
+                    i + 1,
+                    format!("{reason}; preview: {}", masked_preview(lit.value)),
+                ));
+            }
+
+            for arr in byte_array_literals(line) {
+                let by_name = looks_like_credential_name(arr.ident);
+                let entropy = shannon_entropy_bytes(&arr.bytes);
+                let by_entropy = arr.bytes.len() >= BYTE_ARRAY_MIN_LENGTH && entropy >= ENTROPY_THRESHOLD;
+
+                if !by_name && !by_entropy {
+                    continue;
+                }
+
+                let ident = arr.ident;
+                let reason = if by_name && by_entropy {
+                    format!("looks like a {ENTROPY_THRESHOLD:.1}+ bit/byte credential byte array assigned to `{ident}`")
+                } else if by_name {
+                    format!("hardcoded byte array assigned to credential-shaped identifier `{ident}`")
+                } else {
+                    format!("{entropy:.1} bit/byte entropy byte array assigned to `{ident}` looks like key material")
+                };
+
+                findings.push(Finding::new(
+                    self.name(),
+                    Severity::High,
+                    source.display_path(),
+                    i + 1,
+                    format!("{reason}; preview: {}", masked_preview_bytes(&arr.bytes)),
+                ));
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn findings_for(text: &str) -> Vec<Finding> {
+        ExposedSecretsDetector.run(&SourceFile::from_text("test.rs", text))
+    }
+
+    #[test]
+    fn flags_credential_shaped_name_regardless_of_entropy() {
+        let findings = findings_for(r#"const API_KEY: &str = "abc123";"#);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("credential-shaped identifier"));
+    }
+
+    #[test]
+    fn flags_high_entropy_literal_on_an_innocuous_name() {
+        let findings = findings_for(r#"let config_value = "Qx7#mK9$vLp2@nWz5*Yt";"#);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("bit/char"));
+    }
+
+    #[test]
+    fn suppressed_by_trailing_allow_comment() {
+        let findings = findings_for(r#"const API_KEY: &str = "abc123"; // spc-allow: exposed_secrets"#);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn entropy_length_gate_counts_characters_not_bytes() {
+        // 16 distinct 2-byte-in-UTF-8 characters: 16 chars but 32 bytes.
+        // The length gate must use the char count (16, below MIN_LENGTH)
+        // rather than the byte length (32, which would incorrectly pass),
+        // so this should not fire even though entropy clears the threshold.
+        let literal = "àáâãäåæçèéêëìíîï";
+        assert!(literal.chars().count() < MIN_LENGTH);
+        assert!(literal.len() >= MIN_LENGTH);
+        assert!(shannon_entropy(literal) >= ENTROPY_THRESHOLD);
+
+        let findings = findings_for(&format!(r#"let greeting = "{literal}";"#));
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn flags_credential_shaped_byte_array_regardless_of_entropy() {
+        let findings = findings_for("let nonce = [0x00, 0x11, 0x22, 0x33];");
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("credential-shaped identifier"));
+    }
+
+    #[test]
+    fn flags_high_entropy_byte_array_on_an_innocuous_name() {
+        let bytes: [u8; 16] = [0x4e, 0x91, 0x2a, 0xd3, 0x77, 0x08, 0xc1, 0x5f, 0xb6, 0x23, 0xea, 0x9c, 0x34, 0x81, 0x60, 0xf5];
+        assert!(shannon_entropy_bytes(&bytes) >= ENTROPY_THRESHOLD);
+        let literal = bytes.iter().map(|b| format!("0x{b:02x}")).collect::<Vec<_>>().join(", ");
+        let findings = findings_for(&format!("let config_value = [{literal}];"));
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("bit/byte"));
+    }
+
+    #[test]
+    fn short_innocuous_byte_array_is_not_flagged() {
+        let findings = findings_for("let buf = [0x01, 0x02, 0x03];");
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,311 @@
+//! Lightweight source-to-sink taint tracking.
+//!
+//! This is not a real dataflow analysis over the AST — `spc` doesn't parse
+//! Rust, it scans statements textually — but it gives the plain pattern
+//! detectors in `patterns.rs` a notion of provenance: a stdin read that is
+//! never touched again is very different from one concatenated straight
+//! into a shell command. We build a small def-use graph over the
+//! statement-per-line view of the file:
+//!
+//! 1. A binding is marked tainted when it's the destination of a known
+//!    source (`stdin().read_line`, `TcpStream::read`, `File::read_to_string`,
+//!    `std::env::var`).
+//! 2. Every function parameter is also seeded as tainted: `spc` scans one
+//!    file at a time and doesn't resolve call sites, so a parameter is an
+//!    untrusted boundary unless proven otherwise — the same conservative
+//!    call a real taint checker makes at an unresolved call edge.
+//! 3. Taint propagates through plain assignment, `String` concatenation
+//!    (`+`), `format!`, and being passed as a function argument — except
+//!    through a known one-way hash/digest call (`md5::compute`,
+//!    `sha256::digest`, ...), whose output no longer carries the original
+//!    content and is untainted.
+//! 4. A sink call (`Command::new(...).arg(...)`, `println!`, anything that
+//!    looks like a SQL query call) is flagged `tainted_exec` /
+//!    `tainted_format` only if one of its arguments carries taint.
+//!
+//! When a sink finding fires here, the per-pattern hit that matches the same
+//! textual pattern on the same line (currently just `unsanitized_exec`) is
+//! dropped from the report (see `supersedes`) so a tainted value reaching a
+//! sink doesn't get flagged twice under two different rule names. `unsafe_input` and
+//! `network_call` fire on the *source* line, never the sink line, so they
+//! can't be superseded this way and aren't in the list.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::Detector;
+use crate::finding::{Finding, Severity};
+use crate::source::SourceFile;
+
+/// Identifiers whose value is tainted at the current point in the scan.
+/// Reset at the start of every function (see `run`), so a parameter name
+/// reused in a later function — or a stray single-letter one like `x` that
+/// happens to collide with an unrelated format specifier such as `{:x}` —
+/// doesn't stay tainted past the function it belongs to.
+#[derive(Default)]
+struct TaintState {
+    tainted: HashSet<String>,
+}
+
+impl TaintState {
+    fn mark(&mut self, ident: &str) {
+        self.tainted.insert(ident.to_string());
+    }
+
+    fn clear(&mut self, ident: &str) {
+        self.tainted.remove(ident);
+    }
+
+    fn reset(&mut self) {
+        self.tainted.clear();
+    }
+
+    /// Does this expression reference any currently-tainted identifier?
+    fn expr_is_tainted(&self, expr: &str) -> bool {
+        self.tainted.iter().any(|t| references_ident(expr, t))
+    }
+}
+
+fn references_ident(expr: &str, ident: &str) -> bool {
+    Regex::new(&format!(r"\b{}\b", regex::escape(ident)))
+        .unwrap()
+        .is_match(expr)
+}
+
+static BINDING_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(?:let\s+(?:mut\s+)?)?([A-Za-z_]\w*)\s*=\s*(.+?);?\s*$").unwrap());
+
+/// `let <ident> = <rhs>;` / `<ident> = <rhs>;` — returns (ident, rhs).
+fn binding(line: &str) -> Option<(&str, &str)> {
+    let caps = BINDING_RE.captures(line)?;
+    Some((
+        caps.get(1).unwrap().as_str(),
+        line[caps.get(2).unwrap().range()].trim(),
+    ))
+}
+
+static SOURCE_RES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    [
+        r"stdin\s*\(\s*\)\s*\.\s*read_line",
+        r"TcpStream::read\s*\(",
+        r"\.read_to_string\s*\(",
+        r"env::var\s*\(",
+    ]
+    .iter()
+    .map(|p| Regex::new(p).unwrap())
+    .collect()
+});
+
+fn rhs_is_source(rhs: &str) -> bool {
+    SOURCE_RES.iter().any(|re| re.is_match(rhs))
+}
+
+/// One-way hash/digest calls: their result doesn't carry the original
+/// argument's content forward, so it shouldn't inherit its taint.
+static SANITIZING_RES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    [r"md5::compute\s*\(", r"sha256::digest\s*\("]
+        .iter()
+        .map(|p| Regex::new(p).unwrap())
+        .collect()
+});
+
+fn rhs_is_sanitizing(rhs: &str) -> bool {
+    SANITIZING_RES.iter().any(|re| re.is_match(rhs))
+}
+
+static OUT_PARAM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:read_line|read_to_string)\s*\(\s*&mut\s+([A-Za-z_]\w*)").unwrap());
+
+/// `read_line(&mut buffer)` / `read_to_string(&mut contents)` taint the
+/// out-param directly, since the "binding" they affect isn't their return
+/// value but a `&mut` argument.
+fn out_param_source(line: &str) -> Option<&str> {
+    OUT_PARAM_RE.captures(line).map(|c| line[c.get(1).unwrap().range()].as_ref())
+}
+
+static FN_SIGNATURE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"fn\s+\w+\s*\(([^)]*)\)").unwrap());
+static PARAM_NAME_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(?:mut\s+)?(\w+)\s*:").unwrap());
+
+/// Every parameter name declared in a `fn ...(...)` signature on this line.
+fn fn_param_names(line: &str) -> Vec<&str> {
+    let Some(caps) = FN_SIGNATURE_RE.captures(line) else {
+        return Vec::new();
+    };
+    let params: &str = &line[caps.get(1).unwrap().range()];
+    params
+        .split(',')
+        .filter_map(|param| PARAM_NAME_RE.captures(param).map(|c| c.get(1).unwrap().as_str().trim()))
+        .collect()
+}
+
+struct Sink {
+    pattern: &'static str,
+    rule: &'static str,
+    severity: Severity,
+}
+
+static SINKS: &[Sink] = &[
+    Sink { pattern: r#"Command::new\s*\(\s*"sh"\s*\)"#, rule: "tainted_exec", severity: Severity::Critical },
+    Sink { pattern: r"println!\s*\(", rule: "tainted_format", severity: Severity::High },
+    Sink { pattern: r"(?i)\bquery\s*\(", rule: "tainted_format", severity: Severity::High },
+];
+
+static SINK_RES: LazyLock<Vec<Regex>> = LazyLock::new(|| SINKS.iter().map(|s| Regex::new(s.pattern).unwrap()).collect());
+
+pub struct TaintDetector;
+
+impl TaintDetector {
+    /// Rule names whose plain per-line hits should be dropped once taint
+    /// tracking has produced a sink finding on the same line. Only rules
+    /// whose pattern matches the sink line itself belong here.
+    pub fn supersedes() -> &'static [&'static str] {
+        &["unsanitized_exec"]
+    }
+}
+
+impl Detector for TaintDetector {
+    fn name(&self) -> &'static str {
+        "taint"
+    }
+
+    fn run(&self, source: &SourceFile) -> Vec<Finding> {
+        let mut state = TaintState::default();
+        let mut findings = Vec::new();
+
+        for (i, line) in source.lines.iter().enumerate() {
+            let lineno = i + 1;
+
+            if FN_SIGNATURE_RE.is_match(line) {
+                // A new function's signature starts a fresh scope: taint
+                // from the previous function's locals/params doesn't carry
+                // over.
+                state.reset();
+            }
+            for param in fn_param_names(line) {
+                state.mark(param);
+            }
+
+            if let Some(ident) = out_param_source(line) {
+                state.mark(ident);
+            } else if let Some((ident, rhs)) = binding(line) {
+                if rhs_is_sanitizing(rhs) {
+                    state.clear(ident);
+                } else if rhs_is_source(rhs) || state.expr_is_tainted(rhs) {
+                    state.mark(ident);
+                } else {
+                    state.clear(ident);
+                }
+            }
+
+            for (sink, re) in SINKS.iter().zip(SINK_RES.iter()) {
+                if re.is_match(line) && state.expr_is_tainted(line) {
+                    findings.push(Finding::new(
+                        sink.rule,
+                        sink.severity,
+                        source.display_path(),
+                        lineno,
+                        "tainted value from an unvalidated source reaches this sink",
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn findings_for(text: &str) -> Vec<Finding> {
+        TaintDetector.run(&SourceFile::from_text("test.rs", text))
+    }
+
+    #[test]
+    fn tainted_parameter_reaches_shell_sink() {
+        let findings = findings_for(
+            r#"
+fn execute_command(input: &str) {
+    Command::new("sh").arg("-c").arg("echo ".to_string() + input).spawn();
+}
+"#,
+        );
+        assert!(findings.iter().any(|f| f.rule == "tainted_exec" && f.line == 3));
+    }
+
+    #[test]
+    fn untainted_literal_does_not_reach_sink() {
+        let findings = findings_for(
+            r#"
+fn greet() {
+    println!("hello, world");
+}
+"#,
+        );
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn stdin_read_line_taints_println_argument() {
+        let findings = findings_for(
+            r#"
+fn main() {
+    let mut buffer = String::new();
+    io::stdin().read_line(&mut buffer);
+    println!("{}", buffer);
+}
+"#,
+        );
+        assert!(findings.iter().any(|f| f.rule == "tainted_format" && f.line == 5));
+    }
+
+    #[test]
+    fn taint_does_not_carry_a_parameter_name_past_its_own_function() {
+        // `x` is a parameter of `nested_logic` only; it must not stay
+        // tainted into `print_hex` and collide with the `{:x}` format spec.
+        let findings = findings_for(
+            r#"
+fn nested_logic(x: i32, y: i32) {
+    let z = x + y;
+}
+
+fn print_hex() {
+    let n = 255;
+    println!("{:x}", n);
+}
+"#,
+        );
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn hashed_value_does_not_propagate_taint_to_format_sink() {
+        let findings = findings_for(
+            r#"
+fn weak_hash(data: &str) {
+    let digest = md5::compute(data);
+    println!("{:x}", digest);
+}
+"#,
+        );
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn reassignment_clears_taint() {
+        let findings = findings_for(
+            r#"
+fn main() {
+    let mut buffer = String::new();
+    io::stdin().read_line(&mut buffer);
+    buffer = "safe".to_string();
+    println!("{}", buffer);
+}
+"#,
+        );
+        assert!(findings.is_empty());
+    }
+}
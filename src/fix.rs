@@ -0,0 +1,244 @@
+//! Opt-in remediation mode: turns a subset of findings into concrete source
+//! rewrites instead of just reporting them.
+//!
+//! Only the mechanical, unambiguous cases get a rewrite:
+//! - `weak_crypto`: `md5::compute(x)` -> `sha256::digest(x)`.
+//! - `panic_unwrap` on I/O: `<expr>.unwrap()` -> `<expr>?`, which also means
+//!   the enclosing function's return type needs to grow a `Result` — callers
+//!   are responsible for checking that still type-checks, so this rewrite is
+//!   reported but left for the user to confirm before it's applied blindly.
+//! - `unhandled_result`: a bare fallible call becomes `let _ = <expr>;`.
+//!
+//! Rewrites are produced as byte-range replacements first (`Patch`), then
+//! rendered to a unified diff for display. Nothing in this module writes to
+//! disk; applying patches to disk is the caller's explicit choice.
+
+use regex::Regex;
+
+use crate::source::SourceFile;
+
+/// A single, independent rewrite: replace `source.text[start..end]` with
+/// `replacement`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Patch {
+    pub rule: &'static str,
+    pub file: String,
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+/// Collect every mechanical rewrite available for `source`, ordered by
+/// `start` ascending so `apply`/`to_unified_diff` can walk them with a single
+/// forward cursor. The individual `*_patches` helpers each scan the whole
+/// file independently and have no notion of each other's matches, so two
+/// rules can in principle claim overlapping ranges; when that happens the
+/// later-starting one is dropped rather than handed to `apply`, which would
+/// otherwise compute a backwards byte range and panic.
+pub fn propose_patches(source: &SourceFile) -> Vec<Patch> {
+    let mut patches = Vec::new();
+    patches.extend(weak_crypto_patches(source));
+    patches.extend(unhandled_result_patches(source));
+    patches.extend(panic_unwrap_patches(source));
+    patches.sort_by_key(|p| p.start);
+
+    let mut out: Vec<Patch> = Vec::with_capacity(patches.len());
+    for patch in patches {
+        if out.last().is_some_and(|prev| patch.start < prev.end) {
+            continue;
+        }
+        out.push(patch);
+    }
+    out
+}
+
+fn weak_crypto_patches(source: &SourceFile) -> Vec<Patch> {
+    let re = Regex::new(r"md5::compute\s*\(([^)]*)\)").unwrap();
+    re.captures_iter(&source.text)
+        .map(|cap| {
+            let whole = cap.get(0).unwrap();
+            let arg = &cap[1];
+            Patch {
+                rule: "weak_crypto",
+                file: source.display_path(),
+                start: whole.start(),
+                end: whole.end(),
+                replacement: format!("sha256::digest({arg})"),
+            }
+        })
+        .collect()
+}
+
+/// `<fallible call>;` as a bare statement -> `let _ = <fallible call>;`.
+/// Mirrors the shapes `UnhandledResultDetector` flags: a call wrapping a
+/// `read_line`/`read_to_string` (`io::stdin().read_line(...)`), a plain
+/// method call on a receiver (`file.read_to_string(...)`), or a bare
+/// `TcpStream::connect(...)`/`TcpStream::read(...)`. A trailing `// ...`
+/// comment is tolerated since the detector doesn't require the line to be
+/// comment-free.
+fn unhandled_result_patches(source: &SourceFile) -> Vec<Patch> {
+    let fallible = Regex::new(
+        r"(?m)^(\s*)((?:\w+(?:::\w+)*\s*\([^;]*?\.read_line\s*\([^;]*|\w+(?:::\w+)*\s*\([^;]*\.read_to_string\s*\([^;]*|\w+(?:\.\w+)*\s*\.\s*read_to_string\s*\([^;]*|\w+(?:\.\w+)*\s*\.\s*write_all\s*\([^;]*|TcpStream::connect\s*\([^;]*|TcpStream::read\s*\([^;]*))\);\s*(?://[^\r\n]*)?$",
+    )
+    .unwrap();
+    fallible
+        .captures_iter(&source.text)
+        .map(|cap| {
+            let indent = &cap[1];
+            let call = &cap[2];
+            let whole = cap.get(0).unwrap();
+            Patch {
+                rule: "unhandled_result",
+                file: source.display_path(),
+                start: whole.start(),
+                end: whole.end(),
+                replacement: format!("{indent}let _ = {call});"),
+            }
+        })
+        .collect()
+}
+
+/// `<fallible call>.unwrap()` -> `<fallible call>?`. Scoped to known-fallible
+/// I/O calls (`File::open`, `TcpStream::connect`/`read`, `read_to_string`,
+/// `write_all`, `parse`) rather than every `.unwrap()` in the file, since turning an arbitrary
+/// `Option`/`Result` unwrap into `?` can only be right for I/O-shaped calls
+/// here. This rewrite doesn't grow the enclosing function's return type to
+/// `Result` itself — see the module doc — so it's reported like every other
+/// patch but worth a closer look before `--apply`.
+fn panic_unwrap_patches(source: &SourceFile) -> Vec<Patch> {
+    let unwrap_call = Regex::new(
+        r"((?:File::open|TcpStream::connect|TcpStream::read)\s*\([^;]*?\)|\w+(?:\.\w+)*\s*\.\s*(?:read_to_string|write_all|parse(?:::<\w+>)?)\s*\([^;]*?\))\s*\.\s*unwrap\s*\(\s*\)",
+    )
+    .unwrap();
+    unwrap_call
+        .captures_iter(&source.text)
+        .map(|cap| {
+            let call = &cap[1];
+            let whole = cap.get(0).unwrap();
+            Patch {
+                rule: "panic_unwrap",
+                file: source.display_path(),
+                start: whole.start(),
+                end: whole.end(),
+                replacement: format!("{call}?"),
+            }
+        })
+        .collect()
+}
+
+/// Render `patches` (assumed sorted by `start`, non-overlapping) as a unified
+/// diff against `source`.
+pub fn to_unified_diff(source: &SourceFile, patches: &[Patch]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("--- a/{0}\n+++ b/{0}\n", source.display_path()));
+    for patch in patches {
+        let before = &source.text[patch.start..patch.end];
+        let line = source.line_at(patch.start);
+        out.push_str(&format!("@@ line {line} @@\n"));
+        out.push_str(&format!("-{before}\n"));
+        out.push_str(&format!("+{}\n", patch.replacement));
+    }
+    out
+}
+
+/// Apply `patches` to `source.text` and return the rewritten contents.
+/// Patches must be sorted by `start` and non-overlapping; callers that write
+/// the result to disk are expected to have confirmed the rewrite (i.e. the
+/// CLI's `--apply`; `--fix` alone is a dry run).
+pub fn apply(source: &SourceFile, patches: &[Patch]) -> String {
+    let mut out = String::new();
+    let mut cursor = 0;
+    for patch in patches {
+        out.push_str(&source.text[cursor..patch.start]);
+        out.push_str(&patch.replacement);
+        cursor = patch.end;
+    }
+    out.push_str(&source.text[cursor..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patches_come_out_sorted_regardless_of_which_rule_matched_first_in_the_file() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn process(data: &[u8]) {\n    io::stdin().read_line(&mut buf);\n    let hash = md5::compute(data);\n}\n",
+        );
+        let patches = propose_patches(&source);
+        assert_eq!(patches.len(), 2);
+        assert!(patches[0].start < patches[1].start);
+        assert_eq!(patches[0].rule, "unhandled_result");
+        assert_eq!(patches[1].rule, "weak_crypto");
+    }
+
+    #[test]
+    fn apply_does_not_panic_when_rules_match_out_of_file_order() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn process(data: &[u8]) {\n    io::stdin().read_line(&mut buf);\n    let hash = md5::compute(data);\n}\n",
+        );
+        let patches = propose_patches(&source);
+        let rewritten = apply(&source, &patches);
+        assert!(rewritten.contains("let _ = io::stdin().read_line(&mut buf);"));
+        assert!(rewritten.contains("sha256::digest(data)"));
+    }
+
+    #[test]
+    fn overlapping_patches_drop_the_later_one() {
+        // `wrapper(md5::compute(data).read_to_string(&mut s));` matches both
+        // `weak_crypto` (the inner `md5::compute(data)`) and
+        // `unhandled_result` (the whole bare statement) with overlapping
+        // ranges; the one starting later is dropped instead of being handed
+        // to `apply`, which would otherwise compute a backwards byte range.
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn weird(data: &[u8]) {\n    wrapper(md5::compute(data).read_to_string(&mut s));\n}\n",
+        );
+        let patches = propose_patches(&source);
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].rule, "unhandled_result");
+        apply(&source, &patches);
+    }
+
+    #[test]
+    fn panic_unwrap_rewrites_file_open_unwrap_to_question_mark() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn read_input() {\n    let mut file = File::open(\"file.txt\").unwrap();\n}\n",
+        );
+        let patches = propose_patches(&source);
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].rule, "panic_unwrap");
+        let rewritten = apply(&source, &patches);
+        assert!(rewritten.contains("File::open(\"file.txt\")?;"));
+    }
+
+    #[test]
+    fn unhandled_result_covers_plain_method_call_and_tcp_connect() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn read_input() {\n    let mut contents = String::new();\n    file.read_to_string(&mut contents);\n    TcpStream::connect(\"api.example.com:80\"); // No error handling\n}\n",
+        );
+        let patches = propose_patches(&source);
+        assert_eq!(patches.len(), 2);
+        assert!(patches.iter().all(|p| p.rule == "unhandled_result"));
+        let rewritten = apply(&source, &patches);
+        assert!(rewritten.contains("let _ = file.read_to_string(&mut contents);"));
+        assert!(rewritten.contains("let _ = TcpStream::connect(\"api.example.com:80\");"));
+    }
+
+    #[test]
+    fn unhandled_result_tolerates_trailing_comment() {
+        let source = SourceFile::from_text(
+            "test.rs",
+            "fn read_input() {\n    io::stdin().read_line(&mut buffer); // Unvalidated input\n}\n",
+        );
+        let patches = propose_patches(&source);
+        assert_eq!(patches.len(), 1);
+        let rewritten = apply(&source, &patches);
+        assert!(rewritten.contains("let _ = io::stdin().read_line(&mut buffer);"));
+    }
+}
@@ -0,0 +1,29 @@
+pub mod detectors;
+pub mod finding;
+pub mod fix;
+pub mod source;
+
+use detectors::{all_detectors, TaintDetector};
+use finding::Finding;
+use source::SourceFile;
+
+/// Run every detector against `source` and return the findings a user should
+/// actually see: a sink finding from the taint pass replaces the plain
+/// per-pattern hit on the same line so a single tainted value doesn't show up
+/// twice under two different rule names.
+pub fn scan(source: &SourceFile) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut taint_lines: std::collections::HashSet<usize> = std::collections::HashSet::new();
+
+    for detector in all_detectors() {
+        let hits = detector.run(source);
+        if detector.name() == "taint" {
+            taint_lines.extend(hits.iter().map(|f| f.line));
+        }
+        findings.extend(hits);
+    }
+
+    findings.retain(|f| !(TaintDetector::supersedes().contains(&f.rule) && taint_lines.contains(&f.line)));
+    findings.sort_by(|a, b| a.line.cmp(&b.line).then(a.rule.cmp(b.rule)));
+    findings
+}
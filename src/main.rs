@@ -0,0 +1,78 @@
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+use spc::fix;
+use spc::source::SourceFile;
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let fix_mode = take_flag(&mut args, "--fix");
+    let apply = take_flag(&mut args, "--apply");
+
+    if args.is_empty() {
+        eprintln!("usage: spc [--fix [--apply]] <file.rs>...");
+        return ExitCode::FAILURE;
+    }
+
+    let mut had_findings = false;
+    for path in &args {
+        let source = match SourceFile::load(path) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("{path}: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        if fix_mode {
+            had_findings |= run_fix(&source, apply);
+            continue;
+        }
+
+        for finding in spc::scan(&source) {
+            had_findings = true;
+            println!("{finding}");
+        }
+    }
+
+    if had_findings {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    if let Some(pos) = args.iter().position(|a| a == flag) {
+        args.remove(pos);
+        true
+    } else {
+        false
+    }
+}
+
+/// Preview (and, with `--apply`, write) the mechanical rewrites available for
+/// `source`. `--fix` alone is a dry run: it prints the unified diff and the
+/// JSON patch list but never touches disk.
+fn run_fix(source: &SourceFile, apply: bool) -> bool {
+    let patches = fix::propose_patches(source);
+    if patches.is_empty() {
+        return false;
+    }
+
+    println!("{}", fix::to_unified_diff(source, &patches));
+    match serde_json::to_string_pretty(&patches) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("failed to serialize patch list: {e}"),
+    }
+
+    if apply {
+        let rewritten = fix::apply(source, &patches);
+        if let Err(e) = fs::write(&source.path, rewritten) {
+            eprintln!("{}: failed to write fix: {e}", source.display_path());
+        }
+    }
+
+    true
+}
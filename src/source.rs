@@ -0,0 +1,43 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A source file loaded for scanning, split into 1-indexed lines so detectors
+/// can report precise locations.
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub text: String,
+    pub lines: Vec<String>,
+}
+
+impl SourceFile {
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let text = fs::read_to_string(&path)?;
+        let lines = text.lines().map(str::to_string).collect();
+        Ok(Self { path, text, lines })
+    }
+
+    pub fn display_path(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    /// Build a `SourceFile` directly from in-memory text, skipping disk I/O.
+    /// Used by detector unit tests so they don't need fixture files on disk.
+    #[cfg(test)]
+    pub fn from_text(path: &str, text: &str) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            text: text.to_string(),
+            lines: text.lines().map(str::to_string).collect(),
+        }
+    }
+
+    /// Line number (1-indexed) containing the given byte offset into `text`.
+    pub fn line_at(&self, byte_offset: usize) -> usize {
+        self.text[..byte_offset.min(self.text.len())]
+            .bytes()
+            .filter(|&b| b == b'\n')
+            .count()
+            + 1
+    }
+}